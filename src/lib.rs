@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 
-use pyo3::{prelude::*, exceptions::PyIndexError, types::{PyString, PyList, PyIterator}, pyclass::IterNextOutput};
+use pyo3::{prelude::*, exceptions::{PyIndexError, PyValueError}, types::{PyString, PyList, PyIterator}, pyclass::IterNextOutput, buffer::{Element, PyBuffer}};
 
 /// UnionFind.
 #[pyclass]
-struct UnionFind(Vec<usize>);
+struct UnionFind(Vec<usize>, Vec<usize>, usize);
 
 #[pymethods]
 impl UnionFind {
@@ -15,15 +16,34 @@ impl UnionFind {
         for i in 0..size {
             v.push(i);
         }
-        Self(v)
+        Self(v, vec![1; size], size)
     }
 
     fn union(&mut self, a: usize, b: usize) -> PyResult<()> {
-        let b_index = self.find(b)?;
-        self.0[b_index] = self.find(a)?;
+        let a_root = self.find_fast(a)?;
+        let b_root = self.find_fast(b)?;
+        if a_root == b_root {
+            return Ok(());
+        }
+        let (winner, loser) = if self.1[a_root] >= self.1[b_root] {
+            (a_root, b_root)
+        } else {
+            (b_root, a_root)
+        };
+        self.0[loser] = winner;
+        self.1[winner] += self.1[loser];
+        self.2 -= 1;
         Ok(())
     }
 
+    fn is_connected(&mut self, a: usize, b: usize) -> PyResult<bool> {
+        Ok(self.find_fast(a)? == self.find_fast(b)?)
+    }
+
+    fn count(&self) -> usize {
+        self.2
+    }
+
     fn find(&mut self, mut i: usize) -> PyResult<usize> {
         let mut children = Vec::new();
         while let Some(&parent) = self.0.get(i) {
@@ -39,28 +59,38 @@ impl UnionFind {
         Err(PyIndexError::new_err(""))
     }
 
-    fn find_fast(&self, i: usize) -> PyResult<usize> {
-        match self.0.get(i) {
-            Some(&parent) => {
-                if parent == i {
-                    Ok(i)
-                } else { 
-                    self.find_fast(parent)
-                }
-            }
-            None => Err(PyIndexError::new_err(format!("{i} is not in range")))
+    fn find_fast(&mut self, mut i: usize) -> PyResult<usize> {
+        if i >= self.0.len() {
+            return Err(PyIndexError::new_err(format!("{i} is not in range")));
+        }
+        while self.0[i] != i {
+            self.0[i] = self.0[self.0[i]];
+            i = self.0[i];
         }
+        Ok(i)
     }
 
-    fn add(&mut self, parent: Option<usize>) {
-        self.0.push(parent.unwrap_or(self.0.len()))
+    fn add(&mut self, parent: Option<usize>) -> PyResult<()> {
+        self.1.push(1);
+        match parent {
+            Some(p) => {
+                let root = self.find_fast(p)?;
+                self.0.push(p);
+                self.1[root] += 1;
+            }
+            None => {
+                self.0.push(self.0.len());
+                self.2 += 1;
+            }
+        }
+        Ok(())
     }
 
     fn __str__<'py>(&self, py: Python<'py>) -> &'py PyString {
         PyString::new(py, &format!("{:?}", self.0))
     }
 
-    fn groups<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+    fn groups<'py>(&mut self, py: Python<'py>) -> PyResult<&'py PyList> {
         let mut groups: HashMap<_, Vec<usize>> = HashMap::new();
         for i in 0..self.0.len() {
             groups.entry(self.find_fast(i)?).or_default().push(i);
@@ -69,18 +99,293 @@ impl UnionFind {
     }
 }
 
+/// A bitmask integer wide enough to give every queried node in a `gca` call its own bit.
+trait QueryMask: Copy + Eq + std::ops::BitOrAssign {
+    const ZERO: Self;
+    fn bit(index: usize) -> Self;
+    fn full(count: usize) -> Self;
+}
+
+impl QueryMask for u8 {
+    const ZERO: Self = 0;
+    fn bit(index: usize) -> Self {
+        1u8 << index
+    }
+    fn full(count: usize) -> Self {
+        if count == 0 { 0 } else { ((1u16 << count) - 1) as u8 }
+    }
+}
+
+impl QueryMask for u64 {
+    const ZERO: Self = 0;
+    fn bit(index: usize) -> Self {
+        1u64 << index
+    }
+    fn full(count: usize) -> Self {
+        if count == 64 { u64::MAX } else { (1u64 << count) - 1 }
+    }
+}
+
+/// A node hierarchy (parent array per node, supporting multi-parent joint/skin DAGs)
+/// that answers ancestor and greatest-common-ancestor queries.
+#[pyclass]
+struct NodeGraph {
+    parents: Arc<Vec<Vec<usize>>>,
+}
+
+#[pymethods]
+impl NodeGraph {
+    #[new]
+    fn new(parents: Vec<Vec<usize>>) -> PyResult<Self> {
+        for parent_list in &parents {
+            for &p in parent_list {
+                if p >= parents.len() {
+                    return Err(PyIndexError::new_err(format!("{p} is not in range")));
+                }
+            }
+        }
+        Ok(Self { parents: Arc::new(parents) })
+    }
+
+    /// Lazily yields the ancestors of `node` breadth-first (closest first), without
+    /// materializing the full set. This is a BFS over the parent frontier, not a
+    /// topological order: in a DAG with uneven depths a node can be yielded before
+    /// one of its own descendants. Use [`NodeGraph::gca`]'s `topo_order` helper instead
+    /// if a strict children-before-parents order is required.
+    fn ancestors(&self, node: usize) -> PyResult<AncestorIter> {
+        let parents = self.parents.get(node).ok_or_else(|| PyIndexError::new_err(format!("{node} is not in range")))?;
+        Ok(AncestorIter {
+            parents: Arc::clone(&self.parents),
+            seen: parents.iter().copied().collect(),
+            frontier: parents.iter().copied().collect(),
+        })
+    }
+
+    /// Greatest common ancestors of `queries` (at most 64 nodes), found via bitset seeding:
+    /// each query gets its own bit, nodes are swept children-before-parents OR-ing their
+    /// accumulated mask into their parent(s), and nodes whose mask sees every query bit are
+    /// candidates. The maximal candidates (not themselves an ancestor of another candidate)
+    /// are the GCAs.
+    fn gca(&self, queries: Vec<usize>) -> PyResult<Vec<usize>> {
+        if queries.is_empty() {
+            return Err(PyValueError::new_err("gca requires at least one query node"));
+        }
+        if queries.len() > 64 {
+            return Err(PyValueError::new_err("gca supports at most 64 query nodes"));
+        }
+        for &q in &queries {
+            if q >= self.parents.len() {
+                return Err(PyIndexError::new_err(format!("{q} is not in range")));
+            }
+        }
+        let topo_order = self.topo_order()?;
+        let candidates = if queries.len() <= 8 {
+            self.gca_candidates::<u8>(&queries, &topo_order)
+        } else {
+            self.gca_candidates::<u64>(&queries, &topo_order)
+        };
+        Ok(self.maximal_candidates(candidates))
+    }
+}
+
+impl NodeGraph {
+    /// Topological order with every node's children ordered before it.
+    fn topo_order(&self) -> PyResult<Vec<usize>> {
+        let n = self.parents.len();
+        let mut indegree = vec![0usize; n];
+        for parent_list in self.parents.iter() {
+            for &p in parent_list {
+                indegree[p] += 1;
+            }
+        }
+        let mut queue: VecDeque<usize> = (0..n).filter(|&i| indegree[i] == 0).collect();
+        let mut order = Vec::with_capacity(n);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &p in &self.parents[node] {
+                indegree[p] -= 1;
+                if indegree[p] == 0 {
+                    queue.push_back(p);
+                }
+            }
+        }
+        if order.len() != n {
+            return Err(PyValueError::new_err("node graph contains a cycle"));
+        }
+        Ok(order)
+    }
+
+    /// Bitset-seeded sweep: seed each query node with its own bit, OR each node's
+    /// accumulated mask into its parent(s) in children-before-parents order, and
+    /// return every node whose mask has seen all query bits.
+    fn gca_candidates<M: QueryMask>(&self, queries: &[usize], topo_order: &[usize]) -> Vec<usize> {
+        let full = M::full(queries.len());
+        let mut mask = vec![M::ZERO; self.parents.len()];
+        for (bit, &q) in queries.iter().enumerate() {
+            mask[q] |= M::bit(bit);
+        }
+        for &node in topo_order {
+            let accumulated = mask[node];
+            for &p in &self.parents[node] {
+                mask[p] |= accumulated;
+            }
+        }
+        (0..self.parents.len()).filter(|&i| mask[i] == full).collect()
+    }
+
+    /// Drops any candidate that is itself an ancestor of another candidate.
+    fn maximal_candidates(&self, candidates: Vec<usize>) -> Vec<usize> {
+        candidates.iter().copied().filter(|&candidate| {
+            !candidates.iter().any(|&other| other != candidate && self.is_ancestor(candidate, other))
+        }).collect()
+    }
+
+    fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut frontier: VecDeque<usize> = self.parents[node].iter().copied().collect();
+        seen.extend(&frontier);
+        while let Some(current) = frontier.pop_front() {
+            if current == ancestor {
+                return true;
+            }
+            for &p in &self.parents[current] {
+                if seen.insert(p) {
+                    frontier.push_back(p);
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Lazy ancestor iterator returned by [`NodeGraph::ancestors`].
+#[pyclass]
+struct AncestorIter {
+    parents: Arc<Vec<Vec<usize>>>,
+    frontier: VecDeque<usize>,
+    seen: HashSet<usize>,
+}
+
+#[pymethods]
+impl AncestorIter {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> IterNextOutput<usize, &'static str> {
+        match self.frontier.pop_front() {
+            Some(node) => {
+                for &p in &self.parents[node] {
+                    if self.seen.insert(p) {
+                        self.frontier.push_back(p);
+                    }
+                }
+                IterNextOutput::Yield(node)
+            }
+            None => IterNextOutput::Return("ancestors exhausted"),
+        }
+    }
+}
+
 /// Rust thing
 #[pymodule]
 fn lib_helpers(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<UnionFind>()?;
+    m.add_class::<NodeGraph>()?;
+    m.add_class::<AncestorIter>()?;
     m.add_function(wrap_pyfunction!(component_max, m)?)?;
     m.add_function(wrap_pyfunction!(component_min, m)?)?;
+    m.add_function(wrap_pyfunction!(component_bounds, m)?)?;
     // m.add("__doc__", "editor rust").expect("Test");
     Ok(())
 }
 
+/// A numeric type with the buffer protocol and well-defined min/max identities,
+/// so the componentwise buffer scans below can be written once for f32 and f64.
+trait BufferFloat: Element + Copy + PartialOrd {
+    const NEG_INFINITY: Self;
+    const INFINITY: Self;
+}
+
+impl BufferFloat for f32 {
+    const NEG_INFINITY: Self = f32::NEG_INFINITY;
+    const INFINITY: Self = f32::INFINITY;
+}
+
+impl BufferFloat for f64 {
+    const NEG_INFINITY: Self = f64::NEG_INFINITY;
+    const INFINITY: Self = f64::INFINITY;
+}
+
+/// Componentwise max over a flat buffer, chunked into `components`-wide groups.
+fn buffer_component_max<T: BufferFloat>(py: Python, buffer: &PyBuffer<T>, components: usize) -> PyResult<Vec<T>> {
+    let data = buffer.to_vec(py)?;
+    if data.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut maxes = vec![T::NEG_INFINITY; components];
+    for chunk in data.chunks(components) {
+        for (i, &value) in chunk.iter().enumerate() {
+            if value > maxes[i] {
+                maxes[i] = value;
+            }
+        }
+    }
+    Ok(maxes)
+}
+
+/// Componentwise min over a flat buffer, chunked into `components`-wide groups.
+fn buffer_component_min<T: BufferFloat>(py: Python, buffer: &PyBuffer<T>, components: usize) -> PyResult<Vec<T>> {
+    let data = buffer.to_vec(py)?;
+    if data.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut mins = vec![T::INFINITY; components];
+    for chunk in data.chunks(components) {
+        for (i, &value) in chunk.iter().enumerate() {
+            if value < mins[i] {
+                mins[i] = value;
+            }
+        }
+    }
+    Ok(mins)
+}
+
+/// Componentwise `(min, max)` over a flat buffer in a single pass.
+fn buffer_component_bounds<T: BufferFloat>(py: Python, buffer: &PyBuffer<T>, components: usize) -> PyResult<(Vec<T>, Vec<T>)> {
+    let data = buffer.to_vec(py)?;
+    if data.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let mut mins = vec![T::INFINITY; components];
+    let mut maxes = vec![T::NEG_INFINITY; components];
+    for chunk in data.chunks(components) {
+        for (i, &value) in chunk.iter().enumerate() {
+            if value < mins[i] {
+                mins[i] = value;
+            }
+            if value > maxes[i] {
+                maxes[i] = value;
+            }
+        }
+    }
+    Ok((mins, maxes))
+}
+
 #[pyfunction]
-fn component_max<'py>(py: Python<'py>, iter: &'py PyAny) -> PyResult<&'py PyList> {
+#[pyo3(signature = (iter, components=None))]
+fn component_max<'py>(py: Python<'py>, iter: &'py PyAny, components: Option<usize>) -> PyResult<&'py PyList> {
+    if let Some(components) = components {
+        if components == 0 {
+            return Err(PyValueError::new_err("components must be at least 1"));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f32>>() {
+            return Ok(PyList::new(py, buffer_component_max(py, &buffer, components)?));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f64>>() {
+            return Ok(PyList::new(py, buffer_component_max(py, &buffer, components)?));
+        }
+    }
     let mut maxes: Vec<&PyAny> = Vec::new();
     let mut iter = iter.iter()?;
     while let Some(any) = iter.next() {
@@ -100,7 +405,19 @@ fn component_max<'py>(py: Python<'py>, iter: &'py PyAny) -> PyResult<&'py PyList
 }
 
 #[pyfunction]
-fn component_min<'py>(py: Python<'py>, iter: &'py PyAny) -> PyResult<&'py PyList> {
+#[pyo3(signature = (iter, components=None))]
+fn component_min<'py>(py: Python<'py>, iter: &'py PyAny, components: Option<usize>) -> PyResult<&'py PyList> {
+    if let Some(components) = components {
+        if components == 0 {
+            return Err(PyValueError::new_err("components must be at least 1"));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f32>>() {
+            return Ok(PyList::new(py, buffer_component_min(py, &buffer, components)?));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f64>>() {
+            return Ok(PyList::new(py, buffer_component_min(py, &buffer, components)?));
+        }
+    }
     let mut mins: Vec<&PyAny> = Vec::new();
     let mut iter = iter.iter()?;
     while let Some(any) = iter.next() {
@@ -118,3 +435,222 @@ fn component_min<'py>(py: Python<'py>, iter: &'py PyAny) -> PyResult<&'py PyList
     }
     Ok(PyList::new(py, mins))
 }
+
+/// Single-pass componentwise `(min, max)` bounds, for generating accessor min/max together.
+#[pyfunction]
+#[pyo3(signature = (iter, components=None))]
+fn component_bounds<'py>(py: Python<'py>, iter: &'py PyAny, components: Option<usize>) -> PyResult<(&'py PyList, &'py PyList)> {
+    if let Some(components) = components {
+        if components == 0 {
+            return Err(PyValueError::new_err("components must be at least 1"));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f32>>() {
+            let (mins, maxes) = buffer_component_bounds(py, &buffer, components)?;
+            return Ok((PyList::new(py, mins), PyList::new(py, maxes)));
+        }
+        if let Ok(buffer) = iter.extract::<PyBuffer<f64>>() {
+            let (mins, maxes) = buffer_component_bounds(py, &buffer, components)?;
+            return Ok((PyList::new(py, mins), PyList::new(py, maxes)));
+        }
+    }
+    let mut mins: Vec<&PyAny> = Vec::new();
+    let mut maxes: Vec<&PyAny> = Vec::new();
+    let mut iter = iter.iter()?;
+    while let Some(any) = iter.next() {
+        let collection = any?.downcast::<PyAny>()?.iter()?;
+        for (i, item) in collection.enumerate() {
+            let item = item?;
+            if let Some(&min) = mins.get(i) {
+                if item.lt(min)? {
+                    mins[i] = item;
+                }
+            } else {
+                mins.push(item);
+            }
+            if let Some(&max) = maxes.get(i) {
+                if item.gt(max)? {
+                    maxes[i] = item;
+                }
+            } else {
+                maxes.push(item);
+            }
+        }
+    }
+    Ok((PyList::new(py, mins), PyList::new(py, maxes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_two_components_and_decrements_count_once() {
+        let mut uf = UnionFind::new(4);
+        assert_eq!(uf.count(), 4);
+        uf.union(0, 1).unwrap();
+        assert_eq!(uf.count(), 3);
+        assert!(uf.is_connected(0, 1).unwrap());
+        uf.union(0, 1).unwrap();
+        assert_eq!(uf.count(), 3);
+    }
+
+    #[test]
+    fn is_connected_distinguishes_separate_trees() {
+        let mut uf = UnionFind::new(4);
+        uf.union(0, 1).unwrap();
+        uf.union(2, 3).unwrap();
+        assert!(uf.is_connected(0, 1).unwrap());
+        assert!(!uf.is_connected(0, 2).unwrap());
+    }
+
+    #[test]
+    fn find_fast_halves_a_long_chain() {
+        let mut uf = UnionFind::new(5);
+        uf.0 = vec![0, 0, 1, 2, 3]; // degenerate chain: 4 -> 3 -> 2 -> 1 -> 0
+        assert_eq!(uf.find_fast(4).unwrap(), 0);
+        assert_eq!(uf.0[4], 2, "path halving should point 4 at its former grandparent, not straight at the root");
+        assert_eq!(uf.find_fast(4).unwrap(), 0);
+        assert_eq!(uf.0[4], 0, "a second halving pass fully compresses the now-shorter chain");
+    }
+
+    #[test]
+    fn add_with_parent_joins_the_parents_component_without_growing_count() {
+        let mut uf = UnionFind::new(3);
+        uf.union(0, 1).unwrap();
+        assert_eq!(uf.count(), 2);
+        uf.add(Some(0)).unwrap();
+        assert_eq!(uf.count(), 2, "joining node 0's component shouldn't create a new one");
+        assert!(uf.is_connected(0, 3).unwrap());
+        let root = uf.find_fast(0).unwrap();
+        assert_eq!(uf.1[root], 3, "the root's size should grow to cover the new member");
+    }
+
+    #[test]
+    fn add_without_parent_creates_a_new_component() {
+        let mut uf = UnionFind::new(2);
+        uf.add(None).unwrap();
+        assert_eq!(uf.count(), 3);
+        assert!(!uf.is_connected(0, 2).unwrap());
+    }
+
+    fn graph(parents: Vec<Vec<usize>>) -> NodeGraph {
+        NodeGraph::new(parents).unwrap()
+    }
+
+    #[test]
+    fn topo_order_puts_children_before_parents() {
+        // 0 -> 2, 1 -> 2, 2 -> 3
+        let g = graph(vec![vec![2], vec![2], vec![3], vec![]]);
+        let order = g.topo_order().unwrap();
+        let pos = |node: usize| order.iter().position(|&n| n == node).unwrap();
+        assert!(pos(0) < pos(2));
+        assert!(pos(1) < pos(2));
+        assert!(pos(2) < pos(3));
+    }
+
+    #[test]
+    fn topo_order_rejects_a_cycle() {
+        let g = graph(vec![vec![1], vec![0]]);
+        assert!(g.topo_order().is_err());
+    }
+
+    #[test]
+    fn gca_finds_the_single_common_ancestor_in_a_tree() {
+        // 0 -> 2, 1 -> 2, 2 -> 3
+        let g = graph(vec![vec![2], vec![2], vec![3], vec![]]);
+        assert_eq!(g.gca(vec![0, 1]).unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn gca_takes_the_u64_path_over_shared_dag_parents() {
+        // 9 query nodes forces the u64 mask (the u8 path only covers up to 8);
+        // nodes 0..=8 all feed into a single root, node 9.
+        let mut parents = vec![vec![9]; 9];
+        parents.push(vec![]);
+        let g = graph(parents);
+        assert_eq!(g.gca((0..9).collect()).unwrap(), vec![9]);
+    }
+
+    #[test]
+    fn gca_rejects_an_empty_query_set() {
+        let g = graph(vec![vec![]]);
+        assert!(g.gca(vec![]).is_err());
+    }
+
+    #[test]
+    fn gca_rejects_more_than_64_queries() {
+        let g = graph(vec![vec![]; 65]);
+        assert!(g.gca((0..65).collect()).is_err());
+    }
+
+    #[test]
+    fn ancestors_visits_each_shared_ancestor_once_in_a_dag() {
+        // diamond: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3
+        let g = graph(vec![vec![1, 2], vec![3], vec![3], vec![]]);
+        let mut iter = g.ancestors(0).unwrap();
+        let mut seen = Vec::new();
+        while let IterNextOutput::Yield(node) = iter.__next__() {
+            seen.push(node);
+        }
+        seen.sort();
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    fn py_array<'py>(py: Python<'py>, values: &[f32]) -> &'py PyAny {
+        PyModule::import(py, "array").unwrap().call1("array", ("f", values.to_vec())).unwrap()
+    }
+
+    #[test]
+    fn component_max_uses_the_buffer_fast_path() {
+        Python::with_gil(|py| {
+            let buf = py_array(py, &[1.0, 5.0, 2.0, 3.0, 9.0, 0.0]);
+            let result = component_max(py, buf, Some(2)).unwrap();
+            assert_eq!(result.extract::<Vec<f32>>().unwrap(), vec![9.0, 5.0]);
+        });
+    }
+
+    #[test]
+    fn component_min_uses_the_buffer_fast_path() {
+        Python::with_gil(|py| {
+            let buf = py_array(py, &[1.0, 5.0, 2.0, 3.0, 9.0, 0.0]);
+            let result = component_min(py, buf, Some(2)).unwrap();
+            assert_eq!(result.extract::<Vec<f32>>().unwrap(), vec![1.0, 0.0]);
+        });
+    }
+
+    #[test]
+    fn component_bounds_buffer_fast_path_matches_separate_min_and_max() {
+        Python::with_gil(|py| {
+            let buf = py_array(py, &[1.0, 5.0, 2.0, 3.0, 9.0, 0.0]);
+            let (mins, maxes) = component_bounds(py, buf, Some(2)).unwrap();
+            assert_eq!(mins.extract::<Vec<f32>>().unwrap(), vec![1.0, 0.0]);
+            assert_eq!(maxes.extract::<Vec<f32>>().unwrap(), vec![9.0, 5.0]);
+        });
+    }
+
+    #[test]
+    fn component_max_falls_back_to_the_object_protocol_for_non_buffer_input() {
+        Python::with_gil(|py| {
+            let rows = PyList::new(py, [PyList::new(py, [1i64, 5]), PyList::new(py, [3, 2])]);
+            let result = component_max(py, rows, None).unwrap();
+            assert_eq!(result.extract::<Vec<i64>>().unwrap(), vec![3, 5]);
+        });
+    }
+
+    #[test]
+    fn component_max_rejects_zero_components() {
+        Python::with_gil(|py| {
+            let buf = py_array(py, &[1.0]);
+            assert!(component_max(py, buf, Some(0)).is_err());
+        });
+    }
+
+    #[test]
+    fn component_max_returns_an_empty_list_for_an_empty_buffer() {
+        Python::with_gil(|py| {
+            let buf = py_array(py, &[]);
+            let result = component_max(py, buf, Some(3)).unwrap();
+            assert!(result.extract::<Vec<f32>>().unwrap().is_empty());
+        });
+    }
+}